@@ -1,36 +1,381 @@
 extern crate powersoftau;
 extern crate bellman;
+extern crate rayon;
+extern crate blake2_rfc;
+extern crate rand;
+extern crate memmap2;
+extern crate tracing;
+extern crate tracing_subscriber;
 
 use powersoftau::small_bls12_381::Bls12CeremonyParameters;
 use powersoftau::parameters::PowersOfTauParameters;
-use bellman::pairing::bls12_381::{G1Affine, G2Affine};
+use bellman::pairing::bls12_381::{Bls12, Fr, G1Affine, G2Affine};
 use bellman::pairing::*;
-use std::fs::OpenOptions;
-use std::io::{BufReader, BufWriter, Read, Write};
+use blake2_rfc::blake2b::Blake2b;
+use memmap2::Mmap;
+use rand::Rand;
+use rayon::prelude::*;
+use std::fs::{File, OpenOptions};
+use std::io::{BufReader, BufWriter, Read, Seek, SeekFrom, Write};
 use std::env;
+use tracing::{info, info_span};
+
+// Wraps either a buffered file handle or a memory-mapped input, so `reduce` can copy
+// and skip sections the same way regardless of which one was requested.
+enum Input {
+    Buffered(BufReader<File>),
+    Mapped { mmap: Mmap, offset: usize },
+}
+
+impl Input {
+    // Advance past `bytes` without reading them: a plain seek on the file handle, or
+    // just pointer arithmetic on the mapped slice. Either way, the cost no longer
+    // scales with how much we're skipping.
+    fn skip(&mut self, bytes: usize) -> std::io::Result<()> {
+        match self {
+            Input::Buffered(reader) => {
+                reader.seek(SeekFrom::Current(bytes as i64))?;
+            },
+            Input::Mapped { offset, .. } => {
+                *offset += bytes;
+            },
+        }
+        Ok(())
+    }
+}
+
+impl Read for Input {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        match self {
+            Input::Buffered(reader) => reader.read(buf),
+            Input::Mapped { mmap, offset } => {
+                let n = buf.len().min(mmap.len() - *offset);
+                buf[..n].copy_from_slice(&mmap[*offset..*offset + n]);
+                *offset += n;
+                Ok(n)
+            },
+        }
+    }
+}
+
+// Files produced by this tool (`reduce`/`convert`) use the layout:
+//   64-byte hash || 1-byte encoding marker || accumulator body
+// The marker records whether the points in the body are encoded compressed or
+// uncompressed, so `info`/`verify`/`convert` don't have to guess from the file size.
+const ENCODING_UNCOMPRESSED: u8 = 0;
+const ENCODING_COMPRESSED: u8 = 1;
+const HEADER_SIZE: usize = 65;
+
+// Plain ceremony challenge files (the kind `reduce` reads as input) carry only the
+// 64-byte hash, with no encoding marker, and are always uncompressed.
+const LEGACY_HEADER_SIZE: usize = 64;
+
+fn encoding_marker(compressed: bool) -> u8 {
+    if compressed { ENCODING_COMPRESSED } else { ENCODING_UNCOMPRESSED }
+}
 
 fn main() -> Result<(), Box<dyn std::error::Error>> {
     let args: Vec<String> = env::args().collect();
-    if args.len() != 4 {
-        eprintln!("Usage: {} <input_challenge> <output_challenge> <target_power>", args[0]);
-        eprintln!("Example: {} challenge_2_28 challenge_2_20 20", args[0]);
+    let log_format = args.iter()
+        .position(|a| a == "--log-format")
+        .and_then(|i| args.get(i + 1).cloned())
+        .unwrap_or_else(|| "text".to_string());
+    let args = strip_flag_with_value(&args, "--log-format");
+
+    init_tracing(&log_format);
+
+    if args.len() < 2 {
+        print_usage(&args[0]);
+        std::process::exit(1);
+    }
+
+    match args[1].as_str() {
+        "info" => cmd_info(&args),
+        "verify" => cmd_verify(&args),
+        "convert" => cmd_convert(&args),
+        "reduce" => cmd_reduce(&args),
+        other => {
+            eprintln!("Error: unknown subcommand '{}'", other);
+            print_usage(&args[0]);
+            std::process::exit(1);
+        }
+    }
+}
+
+// `--log-format` takes a value, so it can't be filtered out with a plain
+// `.any(|a| a == ...)` like the boolean flags on `reduce` - drop it and its argument
+// together.
+fn strip_flag_with_value(args: &[String], flag: &str) -> Vec<String> {
+    let mut result = Vec::with_capacity(args.len());
+    let mut i = 0;
+    while i < args.len() {
+        if args[i] == flag {
+            i += 2;
+        } else {
+            result.push(args[i].clone());
+            i += 1;
+        }
+    }
+    result
+}
+
+// Progress is emitted as `tracing` spans/events rather than `println!` so an outer
+// ceremony coordinator can consume counts and timings as structured data; `--log-format
+// json` switches the subscriber to newline-delimited JSON for that purpose, otherwise
+// we keep the human-readable formatter.
+fn init_tracing(log_format: &str) {
+    let filter = tracing_subscriber::EnvFilter::try_from_default_env()
+        .unwrap_or_else(|_| tracing_subscriber::EnvFilter::new("info"));
+
+    if log_format == "json" {
+        tracing_subscriber::fmt().with_env_filter(filter).json().init();
+    } else {
+        tracing_subscriber::fmt().with_env_filter(filter).init();
+    }
+}
+
+fn print_usage(program: &str) {
+    eprintln!("Usage:");
+    eprintln!("  {} info <challenge_file>", program);
+    eprintln!("  {} verify <challenge_file>", program);
+    eprintln!("  {} convert <input_file> <output_file> <--to-compressed|--to-uncompressed>", program);
+    eprintln!("  {} reduce <input_file> <output_file> <target_power> [--compressed] [--keep-hash] [--mmap]", program);
+    eprintln!("Add --log-format json to any subcommand for machine-readable progress output.");
+}
+
+// Detects the power, point encoding and header size of a challenge file. Accepts
+// both layouts this tool may be pointed at: its own output (`ENCODING_*`/`HEADER_SIZE`
+// doc comment above) and a plain ceremony challenge file (just the 64-byte hash, no
+// marker, always uncompressed) - the same kind `reduce` itself reads as input - so
+// `info`/`verify`/`convert` work on any challenge file, not only ones this tool produced.
+fn detect_challenge_layout(path: &str) -> Result<(usize, bool, usize), Box<dyn std::error::Error>> {
+    let file_size = std::fs::metadata(path)
+        .expect(&format!("unable to get metadata for '{}'", path))
+        .len() as usize;
+
+    if file_size >= HEADER_SIZE {
+        let mut file = File::open(path).expect(&format!("unable to open '{}'", path));
+        let mut header = [0u8; HEADER_SIZE];
+        file.read_exact(&mut header)?;
+
+        let marker = match header[64] {
+            ENCODING_UNCOMPRESSED => Some(false),
+            ENCODING_COMPRESSED => Some(true),
+            _ => None,
+        };
+
+        if let Some(compressed) = marker {
+            if let Ok(power) = detect_power_from_size(file_size - HEADER_SIZE, compressed) {
+                return Ok((power, compressed, HEADER_SIZE));
+            }
+        }
+    }
+
+    if file_size >= LEGACY_HEADER_SIZE {
+        if let Ok(power) = detect_power_from_size(file_size - LEGACY_HEADER_SIZE, false) {
+            return Ok((power, false, LEGACY_HEADER_SIZE));
+        }
+    }
+
+    Err(format!("file '{}' does not match either the tool's own challenge layout or the plain ceremony layout", path).into())
+}
+
+fn detect_power_from_size(body_size: usize, compressed: bool) -> Result<usize, Box<dyn std::error::Error>> {
+    let (g1_size, g2_size) = if compressed {
+        (Bls12CeremonyParameters::G1_COMPRESSED_BYTE_SIZE, Bls12CeremonyParameters::G2_COMPRESSED_BYTE_SIZE)
+    } else {
+        (Bls12CeremonyParameters::G1_UNCOMPRESSED_BYTE_SIZE, Bls12CeremonyParameters::G2_UNCOMPRESSED_BYTE_SIZE)
+    };
+
+    // Try different powers to find which one matches the file size
+    for power in 10..=27 {
+        let tau_powers_length = 1 << power;
+        let tau_powers_g1_length = (tau_powers_length << 1) - 1;
+
+        let expected_size = (tau_powers_g1_length * g1_size) +
+                           (tau_powers_length * g2_size) +
+                           (tau_powers_length * g1_size) +
+                           (tau_powers_length * g1_size) +
+                           g2_size;
+
+        if expected_size == body_size {
+            return Ok(power);
+        }
+    }
+
+    Err(format!("could not detect power from body size {} bytes", body_size).into())
+}
+
+// The sections of the accumulator body, in on-disk order, as (name, point count).
+fn section_layout(tau_powers_length: usize, tau_powers_g1_length: usize) -> [(&'static str, usize); 5] {
+    [
+        ("tau_powers_g1", tau_powers_g1_length),
+        ("tau_powers_g2", tau_powers_length),
+        ("alpha_tau_powers_g1", tau_powers_length),
+        ("beta_tau_powers_g1", tau_powers_length),
+        ("beta_g2", 1),
+    ]
+}
+
+fn point_size(section: &str, compressed: bool) -> usize {
+    let g1_size = if compressed { Bls12CeremonyParameters::G1_COMPRESSED_BYTE_SIZE } else { Bls12CeremonyParameters::G1_UNCOMPRESSED_BYTE_SIZE };
+    let g2_size = if compressed { Bls12CeremonyParameters::G2_COMPRESSED_BYTE_SIZE } else { Bls12CeremonyParameters::G2_UNCOMPRESSED_BYTE_SIZE };
+
+    match section {
+        "tau_powers_g1" | "alpha_tau_powers_g1" | "beta_tau_powers_g1" => g1_size,
+        "tau_powers_g2" | "beta_g2" => g2_size,
+        other => panic!("unknown section '{}'", other),
+    }
+}
+
+fn cmd_info(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    if args.len() != 3 {
+        eprintln!("Usage: {} info <challenge_file>", args[0]);
+        std::process::exit(1);
+    }
+
+    let challenge_file = &args[2];
+    let (power, compressed, header_size) = detect_challenge_layout(challenge_file)?;
+    let tau_powers_length = 1 << power;
+    let tau_powers_g1_length = (tau_powers_length << 1) - 1;
+
+    println!("Detected power: 2^{} (tau_powers_length: {})", power, tau_powers_length);
+    println!("Encoding: {}", if compressed { "compressed" } else { "uncompressed" });
+    println!("Layout: {}", if header_size == HEADER_SIZE { "tool-produced (with encoding marker)" } else { "plain ceremony challenge" });
+    println!();
+    println!("{:<24} {:>12} {:>12} {:>10}", "section", "points", "bytes", "offset");
+
+    let mut offset = header_size;
+    for (name, count) in section_layout(tau_powers_length, tau_powers_g1_length) {
+        let bytes = count * point_size(name, compressed);
+        println!("{:<24} {:>12} {:>12} {:>10}", name, count, bytes, offset);
+        offset += bytes;
+    }
+
+    println!();
+    println!("Total size: {} bytes", offset);
+
+    Ok(())
+}
+
+fn cmd_verify(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    if args.len() != 3 {
+        eprintln!("Usage: {} verify <challenge_file>", args[0]);
         std::process::exit(1);
     }
 
-    let input_file = &args[1];
-    let output_file = &args[2];
-    let target_power: usize = args[3].parse().expect("target_power must be a valid number");
+    let challenge_file = &args[2];
+    verify_challenge_file(challenge_file, true)?;
+
+    Ok(())
+}
+
+fn cmd_convert(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    if args.len() != 5 {
+        eprintln!("Usage: {} convert <input_file> <output_file> <--to-compressed|--to-uncompressed>", args[0]);
+        std::process::exit(1);
+    }
+
+    let input_file = &args[2];
+    let output_file = &args[3];
+    let target_compressed = match args[4].as_str() {
+        "--to-compressed" => true,
+        "--to-uncompressed" => false,
+        other => {
+            eprintln!("Error: unrecognized conversion mode '{}'", other);
+            std::process::exit(1);
+        }
+    };
+
+    let (power, source_compressed, source_header_size) = detect_challenge_layout(input_file)?;
+    let tau_powers_length = 1 << power;
+    let tau_powers_g1_length = (tau_powers_length << 1) - 1;
+
+    info!(
+        input_file = %input_file, output_file = %output_file, power,
+        source_encoding = if source_compressed { "compressed" } else { "uncompressed" },
+        target_encoding = if target_compressed { "compressed" } else { "uncompressed" },
+        "converting challenge file"
+    );
+
+    let input = OpenOptions::new()
+        .read(true)
+        .open(input_file)
+        .expect(&format!("unable to open input file '{}'", input_file));
+    let mut input = BufReader::new(input);
+
+    let output = OpenOptions::new()
+        .write(true)
+        .create_new(true)
+        .open(output_file)
+        .expect(&format!("unable to create output file '{}'", output_file));
+    let mut output = BufWriter::new(output);
+
+    let mut header = vec![0u8; source_header_size];
+    input.read_exact(&mut header).expect("unable to read header from input file");
+    // Re-encoding changes every serialized byte, so the source hash can never match
+    // the converted body; reserve space and recompute it below instead of copying it.
+    output.write_all(&[0u8; 64]).expect("unable to write hash placeholder to output file");
+
+    let mut output = HashingWriter::new(output);
+    output.write_all(&[encoding_marker(target_compressed)]).expect("unable to write encoding marker to output file");
+
+    for (name, count) in section_layout(tau_powers_length, tau_powers_g1_length) {
+        let bytes = count * point_size(name, source_compressed);
+        let _span = info_span!("section", name, points = count, bytes).entered();
+        match name {
+            "tau_powers_g1" | "alpha_tau_powers_g1" | "beta_tau_powers_g1" => {
+                convert_points::<G1Affine, _, _>(&mut input, &mut output, count, source_compressed, target_compressed)?;
+            },
+            "tau_powers_g2" | "beta_g2" => {
+                convert_points::<G2Affine, _, _>(&mut input, &mut output, count, source_compressed, target_compressed)?;
+            },
+            other => panic!("unknown section '{}'", other),
+        }
+    }
+
+    let (mut output, body_hash) = output.finalize();
+    output.flush().expect("unable to flush output file");
+    output.seek(SeekFrom::Start(0)).expect("unable to seek to hash header");
+    output.write_all(&body_hash).expect("unable to write recomputed hash to output file");
+    output.flush().expect("unable to flush output file");
+
+    info!(output_file = %output_file, "successfully wrote converted challenge");
+
+    verify_challenge_file(output_file, true)?;
+
+    Ok(())
+}
+
+fn cmd_reduce(args: &[String]) -> Result<(), Box<dyn std::error::Error>> {
+    let compressed = args.iter().any(|a| a == "--compressed");
+    let keep_hash = args.iter().any(|a| a == "--keep-hash");
+    let use_mmap = args.iter().any(|a| a == "--mmap");
+    let args: Vec<String> = args.iter()
+        .filter(|a| !matches!(a.as_str(), "--compressed" | "--keep-hash" | "--mmap"))
+        .cloned()
+        .collect();
+
+    if args.len() != 5 {
+        eprintln!("Usage: {} reduce <input_challenge> <output_challenge> <target_power> [--compressed] [--keep-hash] [--mmap]", args[0]);
+        eprintln!("Example: {} reduce challenge_2_28 challenge_2_20 20 --compressed", args[0]);
+        std::process::exit(1);
+    }
+
+    let input_file = &args[2];
+    let output_file = &args[3];
+    let target_power: usize = args[4].parse().expect("target_power must be a valid number");
 
     if target_power > 27 {
         eprintln!("Error: target_power cannot be greater than 27 (current maximum)");
         std::process::exit(1);
     }
 
-    println!("Reducing challenge from input file '{}' to target power 2^{} in output file '{}'", 
-             input_file, target_power, output_file);
+    info!(
+        input_file = %input_file, output_file = %output_file, target_power,
+        "reducing challenge"
+    );
 
-    let parameters = Bls12CeremonyParameters{};
-    
     // Calculate target lengths
     let target_tau_powers_length = 1 << target_power;
     let target_tau_powers_g1_length = (target_tau_powers_length << 1) - 1;
@@ -39,38 +384,51 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     let input_file_size = std::fs::metadata(input_file)
         .expect("unable to get input file metadata")
         .len() as usize;
-    
+
     // Calculate current power from file size
-    // File format: 64-byte hash + accumulator data
+    // File format: 64-byte hash + accumulator data (no encoding marker on raw ceremony input)
     let accumulator_size = input_file_size - 64;
-    let current_power = detect_power_from_size(accumulator_size, &parameters);
+    let current_power = detect_power_from_size(accumulator_size, false)?;
     let current_tau_powers_length = 1 << current_power;
     let current_tau_powers_g1_length = (current_tau_powers_length << 1) - 1;
-    
-    println!("Detected input challenge size:");
-    println!("  Current power: 2^{} (tau_powers_length: {})", current_power, current_tau_powers_length);
-    println!("  tau_powers_g1 length: {}", current_tau_powers_g1_length);
-    println!("  File size: {} bytes", input_file_size);
-    println!();
+
+    info!(
+        current_power, tau_powers_length = current_tau_powers_length,
+        tau_powers_g1_length = current_tau_powers_g1_length, file_size = input_file_size,
+        "detected input challenge size"
+    );
 
     if target_power > current_power {
         eprintln!("Error: target power 2^{} is larger than input power 2^{}", target_power, current_power);
         std::process::exit(1);
     }
 
-    println!("Target accumulator size:");
-    println!("  tau_powers_g1: {} -> {}", current_tau_powers_g1_length, target_tau_powers_g1_length);
-    println!("  tau_powers_g2: {} -> {}", current_tau_powers_length, target_tau_powers_length);
-    println!("  alpha_tau_powers_g1: {} -> {}", current_tau_powers_length, target_tau_powers_length);
-    println!("  beta_tau_powers_g1: {} -> {}", current_tau_powers_length, target_tau_powers_length);
+    info!(
+        target_power,
+        tau_powers_g1 = %format!("{} -> {}", current_tau_powers_g1_length, target_tau_powers_g1_length),
+        tau_powers_g2 = %format!("{} -> {}", current_tau_powers_length, target_tau_powers_length),
+        alpha_tau_powers_g1 = %format!("{} -> {}", current_tau_powers_length, target_tau_powers_length),
+        beta_tau_powers_g1 = %format!("{} -> {}", current_tau_powers_length, target_tau_powers_length),
+        "target accumulator size"
+    );
 
-    // Open files
-    let input = OpenOptions::new()
-        .read(true)
-        .open(input_file)
-        .expect(&format!("unable to open input file '{}'", input_file));
-    
-    let mut input = BufReader::new(input);
+    // Open the input. With --mmap, the file is mapped into memory once up front so
+    // that copying and skipping sections become slicing/pointer-arithmetic instead
+    // of read syscalls.
+    let mut input = if use_mmap {
+        let file = OpenOptions::new()
+            .read(true)
+            .open(input_file)
+            .expect(&format!("unable to open input file '{}'", input_file));
+        let mmap = unsafe { Mmap::map(&file) }.expect("unable to mmap input file");
+        Input::Mapped { mmap, offset: 0 }
+    } else {
+        let file = OpenOptions::new()
+            .read(true)
+            .open(input_file)
+            .expect(&format!("unable to open input file '{}'", input_file));
+        Input::Buffered(BufReader::new(file))
+    };
 
     let output = OpenOptions::new()
         .write(true)
@@ -80,15 +438,35 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
 
     let mut output = BufWriter::new(output);
 
-    // Copy the 64-byte hash
+    // Read the input's 64-byte header hash, but don't trust it for the output: it's
+    // the BLAKE2b-512 digest of the *previous* contribution, and no longer matches
+    // the truncated accumulator body we're about to write. Reserve the space and
+    // fill it in with a hash over the actual reduced body once we know it, unless
+    // the caller explicitly asked to keep the raw truncation via --keep-hash.
     let mut hash = [0u8; 64];
     input.read_exact(&mut hash).expect("unable to read hash from input file");
-    output.write_all(&hash).expect("unable to write hash to output file");
+    if keep_hash {
+        output.write_all(&hash).expect("unable to write hash to output file");
+    } else {
+        output.write_all(&[0u8; 64]).expect("unable to write hash placeholder to output file");
+    }
+
+    let mut output = HashingWriter::new(output);
+
+    // Record which point encoding the body below uses, so a reader doesn't have to
+    // guess from file size alone.
+    output.write_all(&[encoding_marker(compressed)]).expect("unable to write encoding marker to output file");
+
+    if compressed {
+        info!("writing points in compressed form");
+    }
 
     // Stream copy tau_powers_g1 (first target_tau_powers_g1_length points)
-    println!("Copying tau_powers_g1...");
-    stream_copy_points::<G1Affine, _>(&mut input, &mut output, target_tau_powers_g1_length, Bls12CeremonyParameters::G1_UNCOMPRESSED_BYTE_SIZE)?;
-    
+    {
+        let _span = info_span!("section", name = "tau_powers_g1", points = target_tau_powers_g1_length).entered();
+        stream_copy_points::<G1Affine, _>(&mut input, &mut output, target_tau_powers_g1_length, Bls12CeremonyParameters::G1_UNCOMPRESSED_BYTE_SIZE, compressed)?;
+    }
+
     // Skip remaining tau_powers_g1 points
     let skip_g1_points = current_tau_powers_g1_length - target_tau_powers_g1_length;
     if skip_g1_points > 0 {
@@ -96,9 +474,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     // Stream copy tau_powers_g2 (first target_tau_powers_length points)
-    println!("Copying tau_powers_g2...");
-    stream_copy_points::<G2Affine, _>(&mut input, &mut output, target_tau_powers_length, Bls12CeremonyParameters::G2_UNCOMPRESSED_BYTE_SIZE)?;
-    
+    {
+        let _span = info_span!("section", name = "tau_powers_g2", points = target_tau_powers_length).entered();
+        stream_copy_points::<G2Affine, _>(&mut input, &mut output, target_tau_powers_length, Bls12CeremonyParameters::G2_UNCOMPRESSED_BYTE_SIZE, compressed)?;
+    }
+
     // Skip remaining tau_powers_g2 points
     let skip_g2_points = current_tau_powers_length - target_tau_powers_length;
     if skip_g2_points > 0 {
@@ -106,9 +486,11 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     // Stream copy alpha_tau_powers_g1 (first target_tau_powers_length points)
-    println!("Copying alpha_tau_powers_g1...");
-    stream_copy_points::<G1Affine, _>(&mut input, &mut output, target_tau_powers_length, Bls12CeremonyParameters::G1_UNCOMPRESSED_BYTE_SIZE)?;
-    
+    {
+        let _span = info_span!("section", name = "alpha_tau_powers_g1", points = target_tau_powers_length).entered();
+        stream_copy_points::<G1Affine, _>(&mut input, &mut output, target_tau_powers_length, Bls12CeremonyParameters::G1_UNCOMPRESSED_BYTE_SIZE, compressed)?;
+    }
+
     // Skip remaining alpha_tau_powers_g1 points
     let skip_g1_points = current_tau_powers_length - target_tau_powers_length;
     if skip_g1_points > 0 {
@@ -116,158 +498,507 @@ fn main() -> Result<(), Box<dyn std::error::Error>> {
     }
 
     // Stream copy beta_tau_powers_g1 (first target_tau_powers_length points)
-    println!("Copying beta_tau_powers_g1...");
-    stream_copy_points::<G1Affine, _>(&mut input, &mut output, target_tau_powers_length, Bls12CeremonyParameters::G1_UNCOMPRESSED_BYTE_SIZE)?;
-    
+    {
+        let _span = info_span!("section", name = "beta_tau_powers_g1", points = target_tau_powers_length).entered();
+        stream_copy_points::<G1Affine, _>(&mut input, &mut output, target_tau_powers_length, Bls12CeremonyParameters::G1_UNCOMPRESSED_BYTE_SIZE, compressed)?;
+    }
+
     // Skip remaining beta_tau_powers_g1 points
     if skip_g1_points > 0 {
         skip_points(&mut input, skip_g1_points, Bls12CeremonyParameters::G1_UNCOMPRESSED_BYTE_SIZE)?;
     }
 
     // Copy beta_g2 (always just 1 point)
-    println!("Copying beta_g2...");
-    stream_copy_points::<G2Affine, _>(&mut input, &mut output, 1, Bls12CeremonyParameters::G2_UNCOMPRESSED_BYTE_SIZE)?;
+    {
+        let _span = info_span!("section", name = "beta_g2", points = 1).entered();
+        stream_copy_points::<G2Affine, _>(&mut input, &mut output, 1, Bls12CeremonyParameters::G2_UNCOMPRESSED_BYTE_SIZE, compressed)?;
+    }
 
+    let (mut output, body_hash) = output.finalize();
     output.flush().expect("unable to flush output file");
 
-    println!("Successfully wrote reduced challenge to '{}'", output_file);
+    if keep_hash {
+        info!("keeping original challenge hash (--keep-hash was passed)");
+    } else {
+        info!("recomputing challenge hash over the reduced accumulator body");
+        output.seek(SeekFrom::Start(0)).expect("unable to seek to hash header");
+        output.write_all(&body_hash).expect("unable to write recomputed hash to output file");
+        output.flush().expect("unable to flush output file");
+    }
 
-    verify_reduced_challenge(output_file, target_power)?;
+    info!(output_file = %output_file, "successfully wrote reduced challenge");
+
+    verify_challenge_file(output_file, !keep_hash)?;
 
     Ok(())
 }
 
-fn detect_power_from_size(accumulator_size: usize, _parameters: &Bls12CeremonyParameters) -> usize {
-    // Try different powers to find which one matches the file size
-    for power in 10..=27 {
-        let tau_powers_length = 1 << power;
-        let tau_powers_g1_length = (tau_powers_length << 1) - 1;
-        
-        let expected_size = (tau_powers_g1_length * Bls12CeremonyParameters::G1_UNCOMPRESSED_BYTE_SIZE) +
-                           (tau_powers_length * Bls12CeremonyParameters::G2_UNCOMPRESSED_BYTE_SIZE) +
-                           (tau_powers_length * Bls12CeremonyParameters::G1_UNCOMPRESSED_BYTE_SIZE) +
-                           (tau_powers_length * Bls12CeremonyParameters::G1_UNCOMPRESSED_BYTE_SIZE) +
-                           Bls12CeremonyParameters::G2_UNCOMPRESSED_BYTE_SIZE;
-        
-        if expected_size == accumulator_size {
-            return power;
-        }
-    }
-    
-    panic!("Could not detect power from file size {} bytes", accumulator_size);
-}
+// Points at which `stream_copy_points` emits a progress event, so long copies of
+// multi-gigabyte sections show up as a trickle of events rather than one log line at
+// the very end.
+const COPY_PROGRESS_INTERVAL: usize = 1 << 16;
 
 fn stream_copy_points<G: CurveAffine, W: Write>(
     input: &mut dyn Read,
     output: &mut W,
     count: usize,
-    point_size: usize
-) -> std::io::Result<()> {
+    point_size: usize,
+    compressed: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    if !compressed {
+        let mut buffer = vec![0u8; point_size];
+        for i in 0..count {
+            input.read_exact(&mut buffer)?;
+            output.write_all(&buffer)?;
+            if i > 0 && i % COPY_PROGRESS_INTERVAL == 0 {
+                info!(copied = i, total = count, bytes = i * point_size, "copy progress");
+            }
+        }
+        return Ok(());
+    }
+
+    // Decode each uncompressed point to affine and re-encode it compressed, roughly
+    // halving the on-disk size (e.g. 48 bytes per G1 point instead of 96), mirroring
+    // the compressed `Proof::write` path in bellman.
+    use bellman::pairing::EncodedPoint;
     let mut buffer = vec![0u8; point_size];
-    
-    for _ in 0..count {
+    let mut encoded = G::Uncompressed::empty();
+
+    for i in 0..count {
         input.read_exact(&mut buffer)?;
-        output.write_all(&buffer)?;
+        encoded.as_mut().copy_from_slice(&buffer);
+        let point = encoded.into_affine()
+            .map_err(|e| format!("invalid point at index {}: {:?}", i, e))?;
+        output.write_all(point.into_compressed().as_ref())?;
+        if i > 0 && i % COPY_PROGRESS_INTERVAL == 0 {
+            info!(copied = i, total = count, bytes = i * point_size, "copy progress");
+        }
     }
-    
+
     Ok(())
 }
 
-fn skip_points(input: &mut dyn Read, count: usize, point_size: usize) -> std::io::Result<()> {
-    let mut buffer = vec![0u8; point_size];
-    
-    for _ in 0..count {
-        input.read_exact(&mut buffer)?;
+// Like `stream_copy_points`, but handles any combination of source/destination
+// encoding, used by `convert` to re-encode an existing challenge file. The re-encoded
+// points go to a separate output file; the input file is not modified.
+fn convert_points<G: CurveAffine, R: Read, W: Write>(
+    input: &mut R,
+    output: &mut W,
+    count: usize,
+    source_compressed: bool,
+    target_compressed: bool,
+) -> Result<(), Box<dyn std::error::Error>> {
+    use bellman::pairing::EncodedPoint;
+
+    for i in 0..count {
+        let affine = if source_compressed {
+            let mut encoded = G::Compressed::empty();
+            input.read_exact(encoded.as_mut())?;
+            encoded.into_affine()
+        } else {
+            let mut encoded = G::Uncompressed::empty();
+            input.read_exact(encoded.as_mut())?;
+            encoded.into_affine()
+        }.map_err(|e| format!("invalid point at index {}: {:?}", i, e))?;
+
+        if target_compressed {
+            output.write_all(affine.into_compressed().as_ref())?;
+        } else {
+            output.write_all(affine.into_uncompressed().as_ref())?;
+        }
     }
-    
+
     Ok(())
 }
 
-fn verify_reduced_challenge(output_file: &str, target_power: usize) -> Result<(), Box<dyn std::error::Error>> {
-    println!("Verifying reduced challenge file...");
-    
-    // Open the output file
+fn skip_points(input: &mut Input, count: usize, point_size: usize) -> std::io::Result<()> {
+    input.skip(count * point_size)
+}
+
+// Wraps a `Write`/`Read` and feeds every byte that passes through it into a running
+// BLAKE2b-512 hash, so the standard powersoftau challenge header (a hash of the
+// accumulator body) can be produced or checked without buffering the whole body in
+// memory.
+struct HashingWriter<W: Write> {
+    inner: W,
+    hasher: Blake2b,
+}
+
+impl<W: Write> HashingWriter<W> {
+    fn new(inner: W) -> Self {
+        HashingWriter { inner, hasher: Blake2b::with_params(64, &[], &[], &[]) }
+    }
+
+    fn finalize(self) -> (W, [u8; 64]) {
+        let digest = self.hasher.finalize();
+        let mut hash = [0u8; 64];
+        hash.copy_from_slice(digest.as_bytes());
+        (self.inner, hash)
+    }
+}
+
+impl<W: Write> Write for HashingWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+        let written = self.inner.write(buf)?;
+        self.hasher.update(&buf[..written]);
+        Ok(written)
+    }
+
+    fn flush(&mut self) -> std::io::Result<()> {
+        self.inner.flush()
+    }
+}
+
+struct HashingReader<R: Read> {
+    inner: R,
+    hasher: Blake2b,
+}
+
+impl<R: Read> HashingReader<R> {
+    fn new(inner: R) -> Self {
+        HashingReader { inner, hasher: Blake2b::with_params(64, &[], &[], &[]) }
+    }
+
+    fn finalize(self) -> [u8; 64] {
+        let digest = self.hasher.finalize();
+        let mut hash = [0u8; 64];
+        hash.copy_from_slice(digest.as_bytes());
+        hash
+    }
+}
+
+impl<R: Read> Read for HashingReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+        let read = self.inner.read(buf)?;
+        self.hasher.update(&buf[..read]);
+        Ok(read)
+    }
+}
+
+fn verify_challenge_file(challenge_file: &str, check_hash: bool) -> Result<(), Box<dyn std::error::Error>> {
+    info!(challenge_file = %challenge_file, "verifying challenge file");
+
+    let (power, compressed, header_size) = detect_challenge_layout(challenge_file)?;
+    let tau_powers_length = 1 << power;
+    let tau_powers_g1_length = (tau_powers_length << 1) - 1;
+
+    // A plain ceremony challenge's header is the digest of the previous contribution,
+    // not of this body, so only the tool's own layout has a header hash to compare.
+    let check_hash = check_hash && header_size == HEADER_SIZE;
+
     let file = OpenOptions::new()
         .read(true)
-        .open(output_file)
-        .expect(&format!("unable to open output file '{}'", output_file));
-    
+        .open(challenge_file)
+        .expect(&format!("unable to open challenge file '{}'", challenge_file));
+
     let mut reader = BufReader::new(file);
-    
-    // Skip the 64-byte hash
-    let mut hash = [0u8; 64];
-    reader.read_exact(&mut hash)?;
-    
-    // Calculate expected sizes
-    let expected_tau_powers_length = 1 << target_power;
-    let expected_tau_powers_g1_length = (expected_tau_powers_length << 1) - 1;
-    
-    // Manually verify the accumulator structure by reading and checking each section
-    println!("Reading and verifying tau_powers_g1 ({} points)...", expected_tau_powers_g1_length);
-    verify_g1_points(&mut reader, expected_tau_powers_g1_length)?;
-    
-    println!("Reading and verifying tau_powers_g2 ({} points)...", expected_tau_powers_length);
-    verify_g2_points(&mut reader, expected_tau_powers_length)?;
-    
-    println!("Reading and verifying alpha_tau_powers_g1 ({} points)...", expected_tau_powers_length);
-    verify_g1_points(&mut reader, expected_tau_powers_length)?;
-    
-    println!("Reading and verifying beta_tau_powers_g1 ({} points)...", expected_tau_powers_length);
-    verify_g1_points(&mut reader, expected_tau_powers_length)?;
-    
-    println!("Reading and verifying beta_g2 (1 point)...");
-    verify_g2_points(&mut reader, 1)?;
-    
-    println!("✓ Successfully verified reduced challenge file");
-    println!("  - All curve points are valid");
-    println!("  - No points at infinity found");
-    println!("  - Accumulator structure is correct for 2^{}", target_power);
-    
+    let mut stored_hash = [0u8; 64];
+    reader.read_exact(&mut stored_hash)?;
+
+    // Hash the marker byte (if this is the tool's own layout) and every section that
+    // follows it, so we can confirm the stored header is actually the digest of the
+    // body we're about to verify.
+    let mut reader = HashingReader::new(reader);
+    if header_size == HEADER_SIZE {
+        let mut marker = [0u8; 1];
+        reader.read_exact(&mut marker)?;
+    }
+
+    for (name, count) in section_layout(tau_powers_length, tau_powers_g1_length) {
+        let bytes = count * point_size(name, compressed);
+        let _span = info_span!("section", name, points = count, bytes).entered();
+        match name {
+            "tau_powers_g1" | "alpha_tau_powers_g1" | "beta_tau_powers_g1" => {
+                verify_points::<G1Affine, _>(&mut reader, count, compressed, name)?;
+            },
+            "tau_powers_g2" | "beta_g2" => {
+                verify_points::<G2Affine, _>(&mut reader, count, compressed, name)?;
+            },
+            other => panic!("unknown section '{}'", other),
+        }
+    }
+
+    if check_hash {
+        let computed_hash = reader.finalize();
+        if computed_hash != stored_hash {
+            return Err("challenge header hash does not match the BLAKE2b-512 digest of the accumulator body".into());
+        }
+    }
+
+    verify_well_formed(challenge_file, power, compressed, header_size)?;
+
+    info!(
+        power, hash_checked = check_hash,
+        "successfully verified challenge file: all curve points valid, none at infinity, \
+         all in the prime-order subgroup, tau/alpha/beta powers consistent, \
+         accumulator structure correct"
+    );
+
     Ok(())
 }
 
-fn verify_g1_points<R: Read>(reader: &mut R, count: usize) -> Result<(), Box<dyn std::error::Error>> {
-    use bellman::pairing::EncodedPoint;
-    use bellman::pairing::bls12_381::G1Uncompressed;
-    
+// Confirms the accumulator actually encodes consecutive powers of a single tau (and
+// matching alpha, beta), rather than merely being a file of independently-valid
+// curve points. Checking this point-by-point would take one pairing per power; instead
+// we sample one random scalar rho_i per pair, fold everything into a couple of group
+// elements via a random linear combination, and check those with O(1) pairings. Each
+// of the four checks below collapses ~2^power individual ratio checks into two
+// pairings with negligible soundness loss.
+fn verify_well_formed(path: &str, power: usize, compressed: bool, header_size: usize) -> Result<(), Box<dyn std::error::Error>> {
+    let _span = info_span!("well_formedness_check", power).entered();
+    info!("checking tau/alpha/beta consistency via random linear combinations");
+
+    let tau_powers_length = 1 << power;
+    let tau_powers_g1_length = (tau_powers_length << 1) - 1;
+    let g1_size = if compressed { Bls12CeremonyParameters::G1_COMPRESSED_BYTE_SIZE } else { Bls12CeremonyParameters::G1_UNCOMPRESSED_BYTE_SIZE };
+    let g2_size = if compressed { Bls12CeremonyParameters::G2_COMPRESSED_BYTE_SIZE } else { Bls12CeremonyParameters::G2_UNCOMPRESSED_BYTE_SIZE };
+
+    let tau_g1_offset = header_size;
+    let tau_g2_offset = tau_g1_offset + tau_powers_g1_length * g1_size;
+    let alpha_g1_offset = tau_g2_offset + tau_powers_length * g2_size;
+    let beta_g1_offset = alpha_g1_offset + tau_powers_length * g1_size;
+    let beta_g2_offset = beta_g1_offset + tau_powers_length * g1_size;
+
+    // g1 and g1^tau (the first two tau_powers_g1 elements) and g2/g2^tau are the fixed
+    // bases every ratio check below pairs against.
+    let mut tau_g1_bases_reader = reader_at(path, tau_g1_offset)?;
+    let g1: G1Affine = decode_point(&mut tau_g1_bases_reader, compressed)?;
+    let g1_tau: G1Affine = decode_point(&mut tau_g1_bases_reader, compressed)?;
+    let mut tau_g2_reader = reader_at(path, tau_g2_offset)?;
+    let g2: G2Affine = decode_point(&mut tau_g2_reader, compressed)?;
+    let g2_tau: G2Affine = decode_point(&mut tau_g2_reader, compressed)?;
+    let beta_g2: G2Affine = decode_point(&mut reader_at(path, beta_g2_offset)?, compressed)?;
+
+    let mut rng = rand::thread_rng();
+
+    // tau_powers_g1 self-consistency: g1^{tau^i} for consecutive i share the ratio tau.
+    check_tau_ratio(&mut reader_at(path, tau_g1_offset)?, tau_powers_g1_length, compressed, &g2, &g2_tau, &mut rng, "tau_powers_g1")?;
+
+    // tau_powers_g2 self-consistency: g2^{tau^i} for consecutive i share the ratio tau,
+    // checked against the fixed g1/g1^tau bases (the mirror image of the G1 check above).
+    check_g2_tau_ratio(&mut reader_at(path, tau_g2_offset)?, tau_powers_length, compressed, &g1, &g1_tau, &mut rng, "tau_powers_g2")?;
+
+    // alpha_tau_powers_g1 self-consistency: same ratio tau, with an (unknown) alpha scale.
+    check_tau_ratio(&mut reader_at(path, alpha_g1_offset)?, tau_powers_length, compressed, &g2, &g2_tau, &mut rng, "alpha_tau_powers_g1")?;
+
+    // beta_tau_powers_g1 tied to tau_powers_g1 via beta_g2: beta_tau_powers_g1[i] == beta * tau_powers_g1[i].
+    let mut tau_g1_reader = reader_at(path, tau_g1_offset)?;
+    let mut beta_g1_reader = reader_at(path, beta_g1_offset)?;
+    let mut s_tau = <G1Affine as CurveAffine>::Projective::zero();
+    let mut s_beta = <G1Affine as CurveAffine>::Projective::zero();
+
+    for i in 0..tau_powers_length {
+        let tau_point: G1Affine = decode_point(&mut tau_g1_reader, compressed)?;
+        let beta_point: G1Affine = decode_point(&mut beta_g1_reader, compressed)?;
+        let rho = Fr::rand(&mut rng);
+
+        let mut term = tau_point.into_projective();
+        term.mul_assign(rho);
+        s_tau.add_assign(&term);
+
+        let mut term = beta_point.into_projective();
+        term.mul_assign(rho);
+        s_beta.add_assign(&term);
+
+        if i > 0 && i % COPY_PROGRESS_INTERVAL == 0 {
+            info!(label = "beta_tau_powers_g1", checked = i, total = tau_powers_length, "tau ratio check progress");
+        }
+    }
+
+    if Bls12::pairing(s_beta.into_affine(), g2) != Bls12::pairing(s_tau.into_affine(), beta_g2) {
+        return Err("beta_tau_powers_g1 is not consistent with tau_powers_g1 and beta_g2".into());
+    }
+
+    Ok(())
+}
+
+// Folds `count` points from `reader` into a random linear combination and checks
+// that the points form consecutive powers of tau, i.e. S = rho_i * p_i summed over
+// i in 0..count-1 and S' = rho_i * p_{i+1} satisfy e(S, g2^tau) == e(S', g2).
+fn check_tau_ratio<Rng: rand::Rng>(
+    reader: &mut BufReader<File>,
+    count: usize,
+    compressed: bool,
+    g2: &G2Affine,
+    g2_tau: &G2Affine,
+    rng: &mut Rng,
+    label: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut s = <G1Affine as CurveAffine>::Projective::zero();
+    let mut s_shift = <G1Affine as CurveAffine>::Projective::zero();
+    let mut prev: Option<G1Affine> = None;
+
     for i in 0..count {
-        let mut encoded = G1Uncompressed::empty();
-        reader.read_exact(encoded.as_mut())?;
-        
-        match encoded.into_affine() {
-            Ok(point) => {
-                if point.is_zero() {
-                    return Err(format!("Point at infinity found at G1 index {}", i).into());
-                }
-            },
-            Err(e) => {
-                return Err(format!("Invalid G1 point at index {}: {:?}", i, e).into());
-            }
+        let point: G1Affine = decode_point(reader, compressed)?;
+
+        if let Some(prev_point) = prev {
+            let rho = Fr::rand(rng);
+
+            let mut term = prev_point.into_projective();
+            term.mul_assign(rho);
+            s.add_assign(&term);
+
+            let mut term = point.into_projective();
+            term.mul_assign(rho);
+            s_shift.add_assign(&term);
         }
+
+        prev = Some(point);
+
+        if i > 0 && i % COPY_PROGRESS_INTERVAL == 0 {
+            info!(label, checked = i, total = count, "tau ratio check progress");
+        }
+    }
+
+    if Bls12::pairing(s.into_affine(), *g2_tau) != Bls12::pairing(s_shift.into_affine(), *g2) {
+        return Err(format!("{} does not encode consecutive powers of tau", label).into());
     }
-    
+
     Ok(())
 }
 
-fn verify_g2_points<R: Read>(reader: &mut R, count: usize) -> Result<(), Box<dyn std::error::Error>> {
-    use bellman::pairing::EncodedPoint;
-    use bellman::pairing::bls12_381::G2Uncompressed;
-    
+// Mirror image of `check_tau_ratio` for a sequence of G2 points: folds `count` points
+// into a random linear combination and checks e(g1^tau, S) == e(g1, S') where
+// S = rho_i * p_i over i in 0..count-1 and S' = rho_i * p_{i+1}.
+fn check_g2_tau_ratio<Rng: rand::Rng>(
+    reader: &mut BufReader<File>,
+    count: usize,
+    compressed: bool,
+    g1: &G1Affine,
+    g1_tau: &G1Affine,
+    rng: &mut Rng,
+    label: &str,
+) -> Result<(), Box<dyn std::error::Error>> {
+    let mut s = <G2Affine as CurveAffine>::Projective::zero();
+    let mut s_shift = <G2Affine as CurveAffine>::Projective::zero();
+    let mut prev: Option<G2Affine> = None;
+
     for i in 0..count {
-        let mut encoded = G2Uncompressed::empty();
+        let point: G2Affine = decode_point(reader, compressed)?;
+
+        if let Some(prev_point) = prev {
+            let rho = Fr::rand(rng);
+
+            let mut term = prev_point.into_projective();
+            term.mul_assign(rho);
+            s.add_assign(&term);
+
+            let mut term = point.into_projective();
+            term.mul_assign(rho);
+            s_shift.add_assign(&term);
+        }
+
+        prev = Some(point);
+
+        if i > 0 && i % COPY_PROGRESS_INTERVAL == 0 {
+            info!(label, checked = i, total = count, "tau ratio check progress");
+        }
+    }
+
+    if Bls12::pairing(*g1_tau, s.into_affine()) != Bls12::pairing(*g1, s_shift.into_affine()) {
+        return Err(format!("{} does not encode consecutive powers of tau", label).into());
+    }
+
+    Ok(())
+}
+
+fn reader_at(path: &str, offset: usize) -> Result<BufReader<File>, Box<dyn std::error::Error>> {
+    let mut file = File::open(path).expect(&format!("unable to open '{}'", path));
+    file.seek(SeekFrom::Start(offset as u64))?;
+    Ok(BufReader::new(file))
+}
+
+fn decode_point<G: CurveAffine, R: Read>(reader: &mut R, compressed: bool) -> Result<G, Box<dyn std::error::Error>> {
+    use bellman::pairing::EncodedPoint;
+
+    let affine = if compressed {
+        let mut encoded = G::Compressed::empty();
         reader.read_exact(encoded.as_mut())?;
-        
-        match encoded.into_affine() {
-            Ok(point) => {
-                if point.is_zero() {
-                    return Err(format!("Point at infinity found at G2 index {}", i).into());
-                }
-            },
-            Err(e) => {
-                return Err(format!("Invalid G2 point at index {}: {:?}", i, e).into());
-            }
+        encoded.into_affine()
+    } else {
+        let mut encoded = G::Uncompressed::empty();
+        reader.read_exact(encoded.as_mut())?;
+        encoded.into_affine()
+    }.map_err(|e| format!("invalid point: {:?}", e))?;
+
+    Ok(affine)
+}
+
+// Points are validated in chunks so that decoding and subgroup-membership checks can
+// run across threads via rayon, rather than one point at a time on a single core.
+const VERIFY_CHUNK_POINTS: usize = 1 << 14;
+
+fn verify_points<G, R>(reader: &mut R, count: usize, compressed: bool, label: &str) -> Result<(), Box<dyn std::error::Error>>
+where
+    G: CurveAffine + Send + Sync,
+    R: Read,
+{
+    use bellman::pairing::EncodedPoint;
+
+    let point_size = if compressed { G::Compressed::size() } else { G::Uncompressed::size() };
+    let mut index = 0usize;
+    let mut remaining = count;
+
+    while remaining > 0 {
+        let chunk_points = remaining.min(VERIFY_CHUNK_POINTS);
+        let mut buffer = vec![0u8; chunk_points * point_size];
+        reader.read_exact(&mut buffer)?;
+
+        let base_index = index;
+        let error = buffer
+            .par_chunks(point_size)
+            .enumerate()
+            .find_map_any(|(i, bytes)| {
+                decode_and_check_point::<G>(bytes, compressed)
+                    .err()
+                    .map(|e| format!("invalid {} point at index {}: {}", label, base_index + i, e))
+            });
+
+        if let Some(e) = error {
+            return Err(e.into());
         }
+
+        index += chunk_points;
+        remaining -= chunk_points;
+        info!(label, verified = index, total = count, "verify progress");
+    }
+
+    Ok(())
+}
+
+fn decode_and_check_point<G: CurveAffine>(bytes: &[u8], compressed: bool) -> Result<(), String> {
+    use bellman::pairing::EncodedPoint;
+
+    let affine = if compressed {
+        let mut encoded = G::Compressed::empty();
+        encoded.as_mut().copy_from_slice(bytes);
+        encoded.into_affine()
+    } else {
+        let mut encoded = G::Uncompressed::empty();
+        encoded.as_mut().copy_from_slice(bytes);
+        encoded.into_affine()
+    }.map_err(|e| format!("{:?}", e))?;
+
+    if affine.is_zero() {
+        return Err("point at infinity".to_string());
     }
-    
+
+    check_in_prime_order_subgroup(&affine)
+}
+
+// `into_affine()` only confirms the encoded point lies on the curve, not that it lies
+// in the prime-order subgroup. A Powers of Tau accumulator is only sound if every
+// point does, so multiply by the group order `r` and confirm the result is the
+// identity.
+fn check_in_prime_order_subgroup<G: CurveAffine>(point: &G) -> Result<(), String> {
+    use bellman::pairing::{CurveProjective, PrimeField};
+
+    let mut in_subgroup = point.into_projective();
+    in_subgroup.mul_assign(G::Scalar::char());
+
+    if !in_subgroup.is_zero() {
+        return Err("point is not in the prime-order subgroup".to_string());
+    }
+
     Ok(())
 }